@@ -0,0 +1,201 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops;
+
+use num_traits::{One, ToPrimitive, Zero};
+use typenum::{Diff, Integer, Prod, Sum};
+
+use crate::units::Units;
+
+/// A statically dimension-checked quantity: `T` carries the number, and
+/// `L`/`M`/`Ti` are `typenum` type-level integers giving the exponents of
+/// length, mass and time respectively.  Unlike `Units<T>`, whose unit label
+/// is a runtime `SymbolicManip` compared with `normalize_units`, a mismatch
+/// here is a type error instead of a panic: `Dimensioned<T, P1, Z0, Z0>`
+/// (length) and `Dimensioned<T, Z0, Z0, P1>` (time) simply aren't the same
+/// type, so `Add`/`Sub` between them never type-checks.
+pub struct Dimensioned<T, L, M, Ti> {
+    value: T,
+    _dims: PhantomData<(L, M, Ti)>,
+}
+
+impl<T, L, M, Ti> Dimensioned<T, L, M, Ti> {
+    pub fn new(value: T) -> Self {
+        Dimensioned { value, _dims: PhantomData }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Clone, L, M, Ti> Clone for Dimensioned<T, L, M, Ti> {
+    fn clone(&self) -> Self {
+        Dimensioned::new(self.value.clone())
+    }
+}
+
+impl<T: fmt::Debug, L, M, Ti> fmt::Debug for Dimensioned<T, L, M, Ti> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dimensioned").field("value", &self.value).finish()
+    }
+}
+
+impl<T: ops::Add<Output = T>, L, M, Ti> ops::Add for Dimensioned<T, L, M, Ti> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Dimensioned::new(self.value + other.value)
+    }
+}
+
+impl<T: ops::Sub<Output = T>, L, M, Ti> ops::Sub for Dimensioned<T, L, M, Ti> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Dimensioned::new(self.value - other.value)
+    }
+}
+
+impl<T, L1, M1, Ti1, L2, M2, Ti2> ops::Mul<Dimensioned<T, L2, M2, Ti2>> for Dimensioned<T, L1, M1, Ti1>
+    where T: ops::Mul<Output = T>,
+          L1: ops::Add<L2>, M1: ops::Add<M2>, Ti1: ops::Add<Ti2>,
+{
+    type Output = Dimensioned<T, Sum<L1, L2>, Sum<M1, M2>, Sum<Ti1, Ti2>>;
+
+    fn mul(self, other: Dimensioned<T, L2, M2, Ti2>) -> Self::Output {
+        Dimensioned::new(self.value * other.value)
+    }
+}
+
+impl<T, L1, M1, Ti1, L2, M2, Ti2> ops::Div<Dimensioned<T, L2, M2, Ti2>> for Dimensioned<T, L1, M1, Ti1>
+    where T: ops::Div<Output = T>,
+          L1: ops::Sub<L2>, M1: ops::Sub<M2>, Ti1: ops::Sub<Ti2>,
+{
+    type Output = Dimensioned<T, Diff<L1, L2>, Diff<M1, M2>, Diff<Ti1, Ti2>>;
+
+    fn div(self, other: Dimensioned<T, L2, M2, Ti2>) -> Self::Output {
+        Dimensioned::new(self.value / other.value)
+    }
+}
+
+/// The type `Dimensioned<T, L, M, Ti>::pow` raises to when scaling its
+/// exponents by `E`.  Factored out so the method signature doesn't trip
+/// clippy's `type_complexity` lint.
+type Powed<T, L, M, Ti, E> = Dimensioned<T, Prod<L, E>, Prod<M, E>, Prod<Ti, E>>;
+
+impl<T, L, M, Ti> Dimensioned<T, L, M, Ti>
+    where T: Clone + One + ops::Mul<Output = T> + ops::Div<Output = T>
+{
+    /// Raise to an integer power known at the type level, scaling every
+    /// dimension's exponent by `E`.  A negative `E` divides one by the value
+    /// raised to `-E`, mirroring how `Units::normalize_units` turns
+    /// negative exponents into divisions dynamically.
+    pub fn pow<E>(self) -> Powed<T, L, M, Ti, E>
+        where E: Integer, L: ops::Mul<E>, M: ops::Mul<E>, Ti: ops::Mul<E>,
+    {
+        let exponent = E::to_i64();
+        if exponent >= 0 {
+            let mut result = T::one();
+            for _ in 0..exponent {
+                result = result * self.value.clone();
+            }
+            Dimensioned::new(result)
+        } else {
+            let mut denominator = T::one();
+            for _ in 0..(-exponent) {
+                denominator = denominator * self.value.clone();
+            }
+            Dimensioned::new(T::one() / denominator)
+        }
+    }
+}
+
+impl<T, L, M, Ti> Dimensioned<T, L, M, Ti>
+    where L: Integer, M: Integer, Ti: Integer
+{
+    /// Convert into the dynamic `Units<T>` representation, spelling out the
+    /// base units as `m` (length), `kg` (mass) and `s` (time).  Reuses
+    /// `Units`'s own exponent-to-tree builder so both representations agree
+    /// on canonical form.
+    pub fn to_units(&self) -> Units<T>
+        where T: Clone + fmt::Display + ToPrimitive + ops::Mul + ops::Div + Zero + One
+    {
+        let mut entries = Vec::new();
+        let length = L::to_i64();
+        if length != 0 {
+            entries.push((String::from("m"), length));
+        }
+        let mass = M::to_i64();
+        if mass != 0 {
+            entries.push((String::from("kg"), mass));
+        }
+        let time = Ti::to_i64();
+        if time != 0 {
+            entries.push((String::from("s"), time));
+        }
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Units::from_parts(self.value.clone(), Units::<T>::build_unit_tree(&entries))
+    }
+
+    /// Build from the dynamic `Units<T>` representation, trusting the
+    /// caller that its unit label actually matches `L`/`M`/`Ti` -- there's
+    /// no way to check a runtime `SymbolicManip` against type-level
+    /// exponents, so this is the escape hatch for code that already knows
+    /// what it has.
+    pub fn from_units(units: Units<T>) -> Self
+        where T: Clone
+    {
+        Dimensioned::new(units.drop_units())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use typenum::{N1, P1, P2, Z0};
+
+    type Length = Dimensioned<f64, P1, Z0, Z0>;
+    type Time = Dimensioned<f64, Z0, Z0, P1>;
+    type Speed = Dimensioned<f64, P1, Z0, N1>;
+    type Area = Dimensioned<f64, P2, Z0, Z0>;
+
+    #[test]
+    fn mul_and_div_combine_exponents() {
+        let length = Length::new(10.0);
+        let time = Time::new(2.0);
+        let speed: Speed = length.clone() / time;
+        assert_eq!(*speed.value(), 5.0);
+
+        let area: Area = length.clone() * Length::new(3.0);
+        assert_eq!(*area.value(), 30.0);
+    }
+
+    #[test]
+    fn pow_scales_exponents_and_value() {
+        let length = Length::new(3.0);
+        let area: Area = length.pow::<P2>();
+        assert_eq!(*area.value(), 9.0);
+    }
+
+    #[test]
+    fn pow_with_negative_exponent_inverts() {
+        let length = Length::new(4.0);
+        let inverse_length: Dimensioned<f64, N1, Z0, Z0> = length.pow::<N1>();
+        assert_eq!(*inverse_length.value(), 0.25);
+    }
+
+    #[test]
+    fn to_units_and_from_units_round_trip() {
+        let length = Length::new(5.0);
+        let units = length.to_units();
+        assert_eq!(units.drop_units(), 5.0);
+        let back: Length = Dimensioned::from_units(units);
+        assert_eq!(*back.value(), 5.0);
+    }
+}
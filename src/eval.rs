@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops;
+
+use crate::num::{Op, SymbolicManip};
+
+/// An error encountered while evaluating a `SymbolicManip` against a
+/// variable environment.
+#[derive(Debug, PartialEq, Clone)]
+pub enum EvalError {
+    UnboundSymbol(String),
+    UnknownFunction(String),
+    NegativeExponent(String),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &EvalError::UnboundSymbol(ref name) => write!(f, "unbound symbol '{}'", name),
+            &EvalError::UnknownFunction(ref name) => write!(f, "unknown function '{}'", name),
+            &EvalError::NegativeExponent(ref exponent) =>
+                write!(f, "negative exponent '{}' is not supported for this numeric type", exponent),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// The concrete arithmetic a numeric type needs to provide in order for
+/// `SymbolicManip<T>` to be `eval`'d down to a plain `T`.  The basic
+/// operators come from the standard `ops` traits already used elsewhere in
+/// this crate; `Pow`, `abs`, `sqrt` and the `pi` constant don't have a
+/// single idiomatic trait in `std`, so we collect them here.
+pub trait Evaluable:
+    Clone
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::Rem<Output = Self>
+{
+    fn eval_pow(&self, exponent: &Self) -> Result<Self, EvalError>;
+    fn eval_abs(&self) -> Self;
+    fn eval_sqrt(&self) -> Self;
+    fn eval_pi() -> Self;
+}
+
+impl Evaluable for f64 {
+    fn eval_pow(&self, exponent: &Self) -> Result<Self, EvalError> {
+        Ok(self.powf(*exponent))
+    }
+
+    fn eval_abs(&self) -> Self {
+        f64::abs(*self)
+    }
+
+    fn eval_sqrt(&self) -> Self {
+        self.sqrt()
+    }
+
+    fn eval_pi() -> Self {
+        std::f64::consts::PI
+    }
+}
+
+impl Evaluable for i32 {
+    fn eval_pow(&self, exponent: &Self) -> Result<Self, EvalError> {
+        if *exponent < 0 {
+            return Err(EvalError::NegativeExponent(exponent.to_string()));
+        }
+        Ok(self.pow(*exponent as u32))
+    }
+
+    fn eval_abs(&self) -> Self {
+        i32::abs(*self)
+    }
+
+    fn eval_sqrt(&self) -> Self {
+        (*self as f64).sqrt() as i32
+    }
+
+    fn eval_pi() -> Self {
+        3
+    }
+}
+
+impl<T: Evaluable> SymbolicManip<T> {
+    /// Reduce this `SymbolicManip` to a concrete `T`, looking up `Symbol`s
+    /// (other than `pi`) in `env`.
+    pub fn eval(&self, env: &HashMap<String, T>) -> Result<T, EvalError> {
+        match self {
+            &SymbolicManip::Number(ref x) => Ok(x.clone()),
+            &SymbolicManip::Symbol(ref name) if name == "pi" => Ok(T::eval_pi()),
+            &SymbolicManip::Symbol(ref name) => env
+                .get(name)
+                .cloned()
+                .ok_or_else(|| EvalError::UnboundSymbol(name.clone())),
+            &SymbolicManip::BinaryArith(op, ref a, ref b) => {
+                let va = a.eval(env)?;
+                let vb = b.eval(env)?;
+                Ok(match op {
+                    Op::Plus => va + vb,
+                    Op::Minus => va - vb,
+                    Op::Mul => va * vb,
+                    Op::Div => va / vb,
+                    Op::Rem => va % vb,
+                    Op::Pow => va.eval_pow(&vb)?,
+                })
+            }
+            &SymbolicManip::UnaryArith(ref name, ref a) => {
+                let va = a.eval(env)?;
+                match name.as_str() {
+                    "abs" => Ok(va.eval_abs()),
+                    "sqrt" => Ok(va.eval_sqrt()),
+                    other => Err(EvalError::UnknownFunction(other.to_string())),
+                }
+            }
+        }
+    }
+}
+
+impl<T: Clone> SymbolicManip<T> {
+    /// Replace `Symbol`s with other `SymbolicManip`s from `env`, leaving
+    /// unmatched symbols (including `pi`) alone.  Unlike `eval`, this stays
+    /// entirely within the symbolic layer, so it's useful for plugging one
+    /// symbolic expression into another before evaluating the result.
+    pub fn subst(&self, env: &HashMap<String, SymbolicManip<T>>) -> SymbolicManip<T> {
+        match self {
+            &SymbolicManip::Number(ref x) => SymbolicManip::Number(x.clone()),
+            &SymbolicManip::Symbol(ref name) => env
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| SymbolicManip::Symbol(name.clone())),
+            &SymbolicManip::BinaryArith(op, ref a, ref b) => {
+                SymbolicManip::BinaryArith(op, Box::new(a.subst(env)), Box::new(b.subst(env)))
+            }
+            &SymbolicManip::UnaryArith(ref name, ref a) => {
+                SymbolicManip::UnaryArith(name.clone(), Box::new(a.subst(env)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluates_arithmetic() {
+        let env = HashMap::new();
+        let expr: SymbolicManip<i32> = SymbolicManip::BinaryArith(
+            Op::Plus,
+            Box::new(SymbolicManip::Number(2)),
+            Box::new(SymbolicManip::BinaryArith(
+                Op::Mul,
+                Box::new(SymbolicManip::Number(3)),
+                Box::new(SymbolicManip::Number(4)),
+            )),
+        );
+        assert_eq!(expr.eval(&env), Ok(14));
+    }
+
+    #[test]
+    fn evaluates_rem() {
+        let env = HashMap::new();
+        let expr: SymbolicManip<i32> = SymbolicManip::BinaryArith(
+            Op::Rem,
+            Box::new(SymbolicManip::Number(7)),
+            Box::new(SymbolicManip::Number(3)),
+        );
+        assert_eq!(expr.eval(&env), Ok(1));
+    }
+
+    #[test]
+    fn negative_exponent_is_an_error_not_a_panic() {
+        let mut env = HashMap::new();
+        env.insert(String::from("x"), 3);
+        let expr: SymbolicManip<i32> = SymbolicManip::BinaryArith(
+            Op::Pow,
+            Box::new(SymbolicManip::Symbol(String::from("x"))),
+            Box::new(SymbolicManip::Number(-2)),
+        );
+        assert_eq!(
+            expr.eval(&env),
+            Err(EvalError::NegativeExponent(String::from("-2")))
+        );
+    }
+
+    #[test]
+    fn subst_replaces_symbols_but_leaves_pi_alone() {
+        let mut env = HashMap::new();
+        env.insert(String::from("x"), SymbolicManip::Number(5));
+        let expr: SymbolicManip<i32> = SymbolicManip::BinaryArith(
+            Op::Plus,
+            Box::new(SymbolicManip::Symbol(String::from("x"))),
+            Box::new(SymbolicManip::pi()),
+        );
+        let substituted = expr.subst(&env);
+        assert_eq!(
+            substituted,
+            SymbolicManip::BinaryArith(
+                Op::Plus,
+                Box::new(SymbolicManip::Number(5)),
+                Box::new(SymbolicManip::pi()),
+            )
+        );
+    }
+}
@@ -1,9 +1,14 @@
+mod dimensioned;
+mod eval;
+mod modular;
 mod num;
+mod parser;
 mod units;
 use crate::num::*;
 use crate::units::*;
 use std::ops;
 use std::fmt;
+use num_traits::{One, ToPrimitive, Zero};
 use numeric_literals::replace_numeric_literals;
 
 /// This function will, depending on calling context, yield things such as
@@ -23,7 +28,8 @@ fn c<U: Clone>(num: U, unit: &str) -> Units<U> {
 /// Similar to exp_a, but this time with units.
 #[replace_numeric_literals(T::from(literal))]
 fn exp_b<T>() -> Units<T>
-    where T: ops::Add<Output = T> + Clone + ops::Div<Output = T> + From<f64> + PartialEq<T> + fmt::Debug
+    where T: ops::Add<Output = T> + ops::Mul + ops::Div<Output = T> + Clone + From<f64>
+        + PartialEq<T> + fmt::Debug + fmt::Display + Zero + One + ToPrimitive
 {
     (c(96.0, "m") + c(2.0, "m")) / c(10.0, "s")
 }
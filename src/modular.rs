@@ -0,0 +1,252 @@
+use std::fmt;
+use std::ops;
+
+/// An element of `Z/pZ`: an integer-like `T` together with the modulus `p`
+/// it's reduced against.  Usable as the `T` in `SymbolicManip<T>` and
+/// `Units<T>`, so the symbolic and units layers can operate over a finite
+/// ring instead of only plain integers/floats.  `Modular<T>` does not
+/// implement `Zero`, `One` or `ToPrimitive`, so `Units<Modular<T>>` has
+/// `Mul`, `Div` and `Neg` but not `Add` or `Sub`.
+///
+/// A modulus of `T::from(0)` is treated as "unconstrained" rather than a
+/// division by zero: it lets `T::from(literal)` (as used by
+/// `#[replace_numeric_literals]` in `exp_a`) build a `Modular<T>`
+/// without needing to know the modulus up front.  Only `From<i32>` is
+/// implemented, so `exp_b`, which requires `From<f64>`, cannot build one.
+/// Combining an
+/// unconstrained value with one that does carry a modulus adopts that
+/// modulus; combining two different non-zero moduli panics, same as
+/// `Units::add` panics on mismatched units.
+#[derive(Debug, Clone, Copy)]
+pub struct Modular<T> {
+    value: T,
+    modulus: T,
+}
+
+impl<T> Modular<T>
+    where T: Copy + PartialOrd + ops::Add<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    pub fn new(value: T, modulus: T) -> Self {
+        Modular { value: Self::reduce(value, modulus), modulus }
+    }
+
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    pub fn modulus(&self) -> T {
+        self.modulus
+    }
+
+    /// Reduce `value` into `0..modulus`, adjusting negatives so they stay
+    /// non-negative.  A zero modulus means "unconstrained": leave `value`
+    /// untouched rather than reducing mod zero.
+    fn reduce(value: T, modulus: T) -> T {
+        let zero = T::from(0);
+        if modulus == zero {
+            return value;
+        }
+        let mut v = value % modulus;
+        if v < zero {
+            v = v + modulus;
+        }
+        v
+    }
+
+    /// The modulus two operands should combine under: whichever of the two
+    /// is non-zero, preferring `self`'s.  Panics if both carry different
+    /// non-zero moduli, since `a mod p` and `a mod q` aren't comparable.
+    fn combined_modulus(self_modulus: T, other_modulus: T) -> T
+        where T: fmt::Debug
+    {
+        let zero = T::from(0);
+        if self_modulus == zero {
+            other_modulus
+        } else if other_modulus == zero || other_modulus == self_modulus {
+            self_modulus
+        } else {
+            panic!("Mismatched moduli: {:?} vs {:?}", self_modulus, other_modulus);
+        }
+    }
+}
+
+impl<T> ops::Add for Modular<T>
+    where T: Copy + PartialOrd + fmt::Debug + ops::Add<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let modulus = Self::combined_modulus(self.modulus, other.modulus);
+        Modular::new(self.value + other.value, modulus)
+    }
+}
+
+impl<T> ops::Sub for Modular<T>
+    where T: Copy + PartialOrd + fmt::Debug + ops::Add<Output = T> + ops::Sub<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let modulus = Self::combined_modulus(self.modulus, other.modulus);
+        Modular::new(self.value - other.value, modulus)
+    }
+}
+
+impl<T> ops::Mul for Modular<T>
+    where T: Copy + PartialOrd + fmt::Debug + ops::Add<Output = T> + ops::Mul<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let modulus = Self::combined_modulus(self.modulus, other.modulus);
+        Modular::new(self.value * other.value, modulus)
+    }
+}
+
+impl<T> ops::Neg for Modular<T>
+    where T: Copy + PartialOrd + ops::Add<Output = T> + ops::Sub<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Modular::new(self.modulus - self.value, self.modulus)
+    }
+}
+
+impl<T> ops::Rem for Modular<T>
+    where T: Copy + PartialOrd + fmt::Debug + ops::Add<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        let modulus = Self::combined_modulus(self.modulus, other.modulus);
+        Modular::new(self.value % other.value, modulus)
+    }
+}
+
+impl<T> ops::Div for Modular<T>
+    where T: Copy + PartialEq + PartialOrd + fmt::Debug
+        + ops::Add<Output = T> + ops::Sub<Output = T> + ops::Mul<Output = T>
+        + ops::Div<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    type Output = Self;
+
+    /// `a / b` is `a * inverse(b)` in `Z/pZ`, where `inverse(b)` comes from
+    /// the extended Euclidean algorithm: `egcd(b, p)` gives `(g, x, y)`
+    /// with `b*x + p*y = g`.  If `g != 1`, `b` and `p` aren't coprime and no
+    /// inverse exists.
+    fn div(self, other: Self) -> Self {
+        let modulus = Self::combined_modulus(self.modulus, other.modulus);
+        if modulus == T::from(0) {
+            return Modular::new(self.value / other.value, modulus);
+        }
+        let inv = Self::inverse(other.value, modulus);
+        Modular::new(self.value * inv, modulus)
+    }
+}
+
+impl<T> Modular<T>
+    where T: Copy + PartialEq + PartialOrd + ops::Add<Output = T> + ops::Sub<Output = T>
+        + ops::Mul<Output = T> + ops::Div<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    /// Extended Euclidean algorithm: returns `(g, x, y)` such that
+    /// `a*x + b*y = g`, where `g = gcd(a, b)`.
+    fn egcd(a: T, b: T) -> (T, T, T) {
+        let zero = T::from(0);
+        let one = T::from(1);
+        if b == zero {
+            (a, one, zero)
+        } else {
+            let (g, x1, y1) = Self::egcd(b, a % b);
+            (g, y1, x1 - (a / b) * y1)
+        }
+    }
+
+    /// The multiplicative inverse of `b` mod `p`, normalized into `0..p`.
+    /// Panics if `b` and `p` are not coprime (no inverse exists).
+    fn inverse(b: T, p: T) -> T {
+        let (g, x, _) = Self::egcd(b, p);
+        if g != T::from(1) {
+            panic!("no multiplicative inverse: b and p are not coprime");
+        }
+        Self::reduce(x, p)
+    }
+}
+
+impl<T: PartialEq + Copy + From<i32>> PartialEq for Modular<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let zero = T::from(0);
+        if self.modulus == zero || other.modulus == zero || self.modulus == other.modulus {
+            self.value == other.value
+        } else {
+            false
+        }
+    }
+}
+
+impl<T> From<i32> for Modular<T>
+    where T: Copy + PartialOrd + ops::Add<Output = T> + ops::Rem<Output = T> + From<i32>
+{
+    fn from(value: i32) -> Self {
+        Modular::new(T::from(value), T::from(0))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Modular<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_reduces_into_range() {
+        assert_eq!(Modular::new(17, 5).value(), 2);
+        assert_eq!(Modular::new(-1, 5).value(), 4);
+    }
+
+    #[test]
+    fn add_sub_mul_wrap_around_modulus() {
+        let a = Modular::new(3, 7);
+        let b = Modular::new(5, 7);
+        assert_eq!((a + b).value(), 1);
+        assert_eq!((a - b).value(), 5);
+        assert_eq!((a * b).value(), 1);
+    }
+
+    #[test]
+    fn div_uses_the_modular_inverse() {
+        // 3 * 5 == 15 == 1 (mod 7), so 1 / 3 == 5 (mod 7).
+        let one = Modular::new(1, 7);
+        let three = Modular::new(3, 7);
+        assert_eq!((one / three).value(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "not coprime")]
+    fn div_by_non_coprime_panics() {
+        let a = Modular::new(1, 6);
+        let b = Modular::new(2, 6);
+        let _ = a / b;
+    }
+
+    #[test]
+    fn unconstrained_modulus_adopts_the_other_operand_s() {
+        let unconstrained: Modular<i32> = Modular::from(10);
+        let constrained = Modular::new(3, 7);
+        let sum = unconstrained + constrained;
+        assert_eq!(sum.modulus(), 7);
+        assert_eq!(sum.value(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mismatched moduli")]
+    fn combining_different_moduli_panics() {
+        let a = Modular::new(1, 5);
+        let b = Modular::new(1, 7);
+        let _ = a + b;
+    }
+}
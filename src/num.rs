@@ -1,6 +1,8 @@
 use std::fmt;
 use std::ops;
 
+use num_traits::{Num, One, ToPrimitive, Zero};
+
 /// Default Display in terms of debug
 macro_rules! dispdebug {
     ($x:ty) => {
@@ -15,7 +17,7 @@ macro_rules! dispdebug {
 
 /// The "operators" that we're going to support.
 #[derive(Debug, PartialEq, Copy, Clone)]
-pub enum Op {Plus, Minus, Mul, Div, Pow, }
+pub enum Op {Plus, Minus, Mul, Div, Pow, Rem, }
 impl fmt::Display for Op {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let x = match self {
@@ -24,6 +26,7 @@ impl fmt::Display for Op {
             &Op::Mul => "*",
             &Op::Div => "/",
             &Op::Pow => "**",
+            &Op::Rem => "%",
         };
         write!(f, "{}", x)
     }
@@ -75,6 +78,14 @@ impl<T: ops::Div> ops::Div for SymbolicManip<T> {
     }
 }
 
+impl<T: ops::Rem> ops::Rem for SymbolicManip<T> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        SymbolicManip::BinaryArith(Op::Rem, Box::new(self), Box::new(other))
+    }
+}
+
 impl<T> From<T> for SymbolicManip<T> {
     fn from(other: T) -> Self {
         SymbolicManip::Number(other)
@@ -195,6 +206,49 @@ so I pulled it out.
 }
 
 
+impl<T: fmt::Display + PartialEq + From<i32> + Clone + ops::Add> Zero for SymbolicManip<T> {
+    fn zero() -> Self {
+        SymbolicManip::Number(T::from(0))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.simplify() == Self::zero()
+    }
+}
+
+impl<T: From<i32> + ops::Mul> One for SymbolicManip<T> {
+    fn one() -> Self {
+        SymbolicManip::Number(T::from(1))
+    }
+}
+
+impl<T> Num for SymbolicManip<T>
+    where T: fmt::Display + PartialEq + From<i32> + Clone
+        + ops::Add + ops::Sub + ops::Mul + ops::Div + ops::Rem + Num
+{
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(str, radix).map(SymbolicManip::Number)
+    }
+}
+
+impl<T: ToPrimitive> ToPrimitive for SymbolicManip<T> {
+    fn to_i64(&self) -> Option<i64> {
+        match self {
+            &SymbolicManip::Number(ref x) => x.to_i64(),
+            _ => None,
+        }
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        match self {
+            &SymbolicManip::Number(ref x) => x.to_u64(),
+            _ => None,
+        }
+    }
+}
+
 impl<T: fmt::Display> fmt::Display for SymbolicManip<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(&self.pretty_show())
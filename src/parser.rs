@@ -0,0 +1,324 @@
+use std::fmt;
+use std::ops;
+use std::ops::Neg;
+use std::str::FromStr;
+
+use crate::num::{Op, SymbolicManip};
+
+/// An error encountered while parsing an algebraic expression into a
+/// [`SymbolicManip`].  Carries the character offset into the input where the
+/// problem was noticed, so callers can point the user at the right spot.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "parse error at position {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(String),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    StarStar,
+    LParen,
+    RParen,
+}
+
+#[derive(Debug, Clone)]
+struct Spanned {
+    token: Token,
+    position: usize,
+}
+
+/// Turn a source string into a stream of spanned tokens.
+fn tokenize(input: &str) -> Result<Vec<Spanned>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let token = match c {
+            '+' => { i += 1; Token::Plus }
+            '-' => { i += 1; Token::Minus }
+            '/' => { i += 1; Token::Slash }
+            '(' => { i += 1; Token::LParen }
+            ')' => { i += 1; Token::RParen }
+            '*' => {
+                i += 1;
+                if i < chars.len() && chars[i] == '*' {
+                    i += 1;
+                    Token::StarStar
+                } else {
+                    Token::Star
+                }
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut s = String::new();
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                Token::Number(s)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut s = String::new();
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                Token::Ident(s)
+            }
+            other => {
+                return Err(ParseError {
+                    message: format!("unexpected character '{}'", other),
+                    position: start,
+                });
+            }
+        };
+        tokens.push(Spanned { token, position: start });
+    }
+
+    Ok(tokens)
+}
+
+/// Precedence-climbing parser.  `tokens` is consumed via `pos`, which tracks
+/// our place in the token stream.
+struct Parser<'a> {
+    tokens: &'a [Spanned],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Spanned> {
+        self.tokens.get(self.pos)
+    }
+
+    fn end_position(&self) -> usize {
+        match self.tokens.last() {
+            Some(s) => s.position + 1,
+            None => 0,
+        }
+    }
+
+    fn bump(&mut self) -> Option<Spanned> {
+        let t = self.tokens.get(self.pos).cloned();
+        if t.is_some() {
+            self.pos += 1;
+        }
+        t
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<(), ParseError> {
+        match self.bump() {
+            Some(Spanned { ref token, .. }) if token == expected => Ok(()),
+            Some(Spanned { token, position }) => Err(ParseError {
+                message: format!("expected {:?}, found {:?}", expected, token),
+                position,
+            }),
+            None => Err(ParseError {
+                message: format!("expected {:?}, found end of input", expected),
+                position: self.end_position(),
+            }),
+        }
+    }
+
+    /// Binding power of a binary operator: (left, right).  Higher binds
+    /// tighter.  `**` is right-associative, so its right binding power is
+    /// lower than its left.
+    fn infix_binding_power(token: &Token) -> Option<(u8, u8)> {
+        match token {
+            Token::Plus | Token::Minus => Some((1, 2)),
+            Token::Star | Token::Slash => Some((3, 4)),
+            Token::StarStar => Some((6, 5)),
+            _ => None,
+        }
+    }
+
+    /// Minimum binding power a unary minus's operand must have in order to
+    /// be swallowed by the unary minus rather than left for the caller.
+    /// Sits between `*`/`/` and `**`, so `-2 * 3 == (-2) * 3` but
+    /// `-2 ** 2 == -(2 ** 2)`.
+    const UNARY_MINUS_BP: u8 = 5;
+
+    fn parse_expr<T: FromStr + Clone + ops::Neg + ops::Mul + From<i32>>(&mut self, min_bp: u8) -> Result<SymbolicManip<T>, ParseError> {
+        let mut lhs = self.parse_prefix()?;
+
+        loop {
+            let op = match self.peek() {
+                Some(Spanned { token, .. }) => match Self::infix_binding_power(token) {
+                    Some(bp) if bp.0 >= min_bp => Some((token.clone(), bp)),
+                    _ => None,
+                },
+                None => None,
+            };
+
+            let (token, (_, rbp)) = match op {
+                Some(x) => x,
+                None => break,
+            };
+
+            self.bump();
+            let rhs = self.parse_expr(rbp)?;
+            let op = match token {
+                Token::Plus => Op::Plus,
+                Token::Minus => Op::Minus,
+                Token::Star => Op::Mul,
+                Token::Slash => Op::Div,
+                Token::StarStar => Op::Pow,
+                _ => unreachable!(),
+            };
+            lhs = SymbolicManip::BinaryArith(op, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    fn parse_prefix<T: FromStr + Clone + ops::Neg + ops::Mul + From<i32>>(&mut self) -> Result<SymbolicManip<T>, ParseError> {
+        match self.bump() {
+            Some(Spanned { token: Token::Minus, .. }) => {
+                let operand = self.parse_expr(Self::UNARY_MINUS_BP)?;
+                Ok(operand.neg())
+            }
+            Some(Spanned { token: Token::Number(s), position }) => {
+                T::from_str(&s)
+                    .map(SymbolicManip::Number)
+                    .map_err(|_| ParseError {
+                        message: format!("invalid numeric literal '{}'", s),
+                        position,
+                    })
+            }
+            Some(Spanned { token: Token::Ident(name), .. }) => {
+                if name == "pi" {
+                    return Ok(SymbolicManip::pi());
+                }
+                if let Some(Spanned { token: Token::LParen, .. }) = self.peek() {
+                    self.bump();
+                    let arg = self.parse_expr(0)?;
+                    self.expect(&Token::RParen)?;
+                    Ok(SymbolicManip::UnaryArith(name, Box::new(arg)))
+                } else {
+                    Ok(SymbolicManip::Symbol(name))
+                }
+            }
+            Some(Spanned { token: Token::LParen, .. }) => {
+                let inner = self.parse_expr(0)?;
+                self.expect(&Token::RParen)?;
+                Ok(inner)
+            }
+            Some(Spanned { token, position }) => Err(ParseError {
+                message: format!("unexpected token {:?}", token),
+                position,
+            }),
+            None => Err(ParseError {
+                message: String::from("unexpected end of input"),
+                position: self.end_position(),
+            }),
+        }
+    }
+}
+
+/// Parse an algebraic expression such as `"3 * (x + 2) ** 2 - abs(y) / pi"`
+/// into a [`SymbolicManip<T>`].
+///
+/// Numeric literals are parsed through `T::from_str`; bare identifiers
+/// become `Symbol`s (with `pi` mapped to the symbolic constant), and
+/// `name(arg)` call syntax becomes `UnaryArith`.
+pub fn parse<T: FromStr + Clone + ops::Neg + ops::Mul + From<i32>>(input: &str) -> Result<SymbolicManip<T>, ParseError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens: &tokens, pos: 0 };
+    let result = parser.parse_expr(0)?;
+    if let Some(Spanned { token, position }) = parser.peek() {
+        return Err(ParseError {
+            message: format!("unexpected trailing token {:?}", token),
+            position: *position,
+        });
+    }
+    Ok(result)
+}
+
+impl<T: FromStr + Clone + ops::Neg + ops::Mul + From<i32>> FromStr for SymbolicManip<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_precedence() {
+        let expr: SymbolicManip<i32> = parse("1 + 2 * 3").unwrap();
+        assert_eq!(expr.to_rpn(), "1 2 3 * +");
+    }
+
+    #[test]
+    fn pow_is_right_associative() {
+        let expr: SymbolicManip<i32> = parse("2 ** 3 ** 2").unwrap();
+        assert_eq!(expr.to_rpn(), "2 3 2 ** **");
+    }
+
+    #[test]
+    fn unary_minus_binds_looser_than_pow() {
+        // -2 ** 2 == -(2 ** 2), not (-2) ** 2
+        let expr: SymbolicManip<i32> = parse("-2 ** 2").unwrap();
+        assert_eq!(expr.to_rpn(), "2 2 ** -1 *");
+    }
+
+    #[test]
+    fn unary_minus_binds_tighter_than_mul() {
+        // -2 * 3 == (-2) * 3
+        let expr: SymbolicManip<i32> = parse("-2 * 3").unwrap();
+        assert_eq!(expr.to_rpn(), "2 -1 * 3 *");
+    }
+
+    #[test]
+    fn parses_function_calls_and_pi() {
+        let expr: SymbolicManip<f64> = parse("abs(x) / pi").unwrap();
+        assert_eq!(expr.pretty_show(), "abs(x)/pi");
+    }
+
+    #[test]
+    fn parses_parens() {
+        let expr: SymbolicManip<i32> = parse("3 * (x + 2)").unwrap();
+        assert_eq!(expr.pretty_show(), "3*(x+2)");
+    }
+
+    #[test]
+    fn reports_error_position_on_unexpected_char() {
+        let err = parse::<i32>("1 + @").unwrap_err();
+        assert_eq!(err.position, 4);
+    }
+
+    #[test]
+    fn reports_error_on_unbalanced_parens() {
+        let err = parse::<i32>("(1 + 2").unwrap_err();
+        assert_eq!(err.position, 6);
+    }
+
+    #[test]
+    fn from_str_matches_parse() {
+        let a: SymbolicManip<i32> = "x + 1".parse().unwrap();
+        let b: SymbolicManip<i32> = parse("x + 1").unwrap();
+        assert_eq!(a, b);
+    }
+}
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
 use std::fmt;
 use std::ops;
 use std::ops::Neg;
 
+use num_traits::{One, ToPrimitive, Zero};
+
 use crate::num::*;
 
 /// New data type: Units.  A Units type contains a number
@@ -13,19 +16,27 @@ pub struct Units<T> {
     unit: SymbolicManip<T>
 }
 
-impl<T: ops::Add<Output = T> + PartialEq + fmt::Debug> ops::Add for Units<T> {
+impl<T> ops::Add for Units<T>
+    where T: ops::Add<Output = T> + ops::Mul + ops::Div + PartialEq + fmt::Debug + fmt::Display
+        + Clone + Zero + One + ToPrimitive
+{
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
-        if self.unit != other.unit {
-            panic!("Mismatched units in add: {:?} vs {:?}", self.unit, other.unit);
+        let self_unit = self.normalize_units();
+        let other_unit = other.normalize_units();
+        if self_unit.unit != other_unit.unit {
+            panic!("Mismatched units in add: {:?} vs {:?}", self_unit.unit, other_unit.unit);
         }
         let x: T = self.number + other.number;
-        Units {number: x, ..self}
+        Units {number: x, unit: self_unit.unit}
     }
 }
 
-impl<T: ops::Add<Output = T> + ops::Sub<Output = T> + ops::Neg<Output = T> + PartialEq + fmt::Debug> ops::Sub for Units<T> {
+impl<T> ops::Sub for Units<T>
+    where T: ops::Add<Output = T> + ops::Sub<Output = T> + ops::Neg<Output = T> + ops::Mul + ops::Div
+        + PartialEq + fmt::Debug + fmt::Display + Clone + Zero + One + ToPrimitive
+{
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -73,5 +84,164 @@ impl<T: Clone> Units<T> {
     pub fn drop_units(&self) -> T {
         self.number.clone()
     }
+
+    /// Assemble a `Units` from an already-built unit tree.  `pub(crate)` so
+    /// other modules in the crate (such as the static `Dimensioned` type)
+    /// can hand it a canonical tree without going through `new`'s
+    /// single-`Symbol` constructor.
+    pub(crate) fn from_parts(number: T, unit: SymbolicManip<T>) -> Self {
+        Units { number, unit }
+    }
+}
+
+impl<T> Units<T>
+    where T: Clone + fmt::Display + ToPrimitive + ops::Mul + ops::Div + Zero + One
+{
+    /// Fold `self.unit` into a canonical product of `name ** exponent`
+    /// terms, one per base unit, with dimensionless factors (zero
+    /// exponent) dropped and negative exponents written as divisions.
+    /// This makes e.g. `m * m` and `m ** 2` compare equal, and lets
+    /// `m / m` cancel down to dimensionless.
+    pub fn normalize_units(&self) -> Units<T> {
+        let mut exponents = HashMap::new();
+        Self::collect_exponents(&self.unit, 1, &mut exponents);
+
+        let mut entries: Vec<(String, i64)> = exponents
+            .into_iter()
+            .filter(|&(_, exponent)| exponent != 0)
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Units {
+            number: self.number.clone(),
+            unit: Self::build_unit_tree(&entries),
+        }
+    }
+
+    /// Walk a unit `SymbolicManip`, accumulating the exponent of every base
+    /// unit `Symbol` into `exponents`.  `Mul` contributes `sign`, `Div`
+    /// negates it on the right-hand side, and an integer-literal `Pow`
+    /// scales it.  Anything else (an unexpected operator, or a `Pow` with a
+    /// non-integer exponent) is treated as its own opaque unit label, so
+    /// normalization is always total rather than panicking.
+    fn collect_exponents(tree: &SymbolicManip<T>, sign: i64, exponents: &mut HashMap<String, i64>) {
+        match tree {
+            &SymbolicManip::Symbol(ref name) => {
+                *exponents.entry(name.clone()).or_insert(0) += sign;
+            }
+            &SymbolicManip::Number(_) => (),
+            &SymbolicManip::BinaryArith(Op::Mul, ref a, ref b) => {
+                Self::collect_exponents(a, sign, exponents);
+                Self::collect_exponents(b, sign, exponents);
+            }
+            &SymbolicManip::BinaryArith(Op::Div, ref a, ref b) => {
+                Self::collect_exponents(a, sign, exponents);
+                Self::collect_exponents(b, -sign, exponents);
+            }
+            &SymbolicManip::BinaryArith(Op::Pow, ref base, ref exponent) => {
+                match **exponent {
+                    SymbolicManip::Number(ref n) if n.to_i64().is_some()
+                        && n.to_f64() == n.to_i64().map(|i| i as f64) => {
+                        Self::collect_exponents(base, sign * n.to_i64().unwrap(), exponents);
+                    }
+                    _ => {
+                        *exponents.entry(tree.pretty_show()).or_insert(0) += sign;
+                    }
+                }
+            }
+            _ => {
+                *exponents.entry(tree.pretty_show()).or_insert(0) += sign;
+            }
+        }
+    }
+
+    /// Reconstruct a sorted `name ** exponent` product from normalized
+    /// `(name, exponent)` entries, with positive exponents multiplied into
+    /// the numerator and negative ones into a divided-out denominator.
+    pub(crate) fn build_unit_tree(entries: &[(String, i64)]) -> SymbolicManip<T> {
+        // Build a literal for a small positive integer without requiring
+        // `T: From<i32>`, so this works for `T`s (like a nested
+        // `SymbolicManip`) that only give us `Zero`/`One`.
+        let int_literal = |n: i64| -> T {
+            let one = T::one();
+            let mut acc = T::zero();
+            for _ in 0..n {
+                acc = acc + one.clone();
+            }
+            acc
+        };
+
+        let term = |name: &str, exponent: i64| -> SymbolicManip<T> {
+            let base = SymbolicManip::Symbol(name.to_string());
+            if exponent == 1 {
+                base
+            } else {
+                base.pow(SymbolicManip::Number(int_literal(exponent)))
+            }
+        };
+
+        let product = |terms: Vec<SymbolicManip<T>>| -> Option<SymbolicManip<T>> {
+            let mut iter = terms.into_iter();
+            let first = iter.next()?;
+            Some(iter.fold(first, |acc, t| acc * t))
+        };
+
+        let numerator: Vec<SymbolicManip<T>> = entries.iter()
+            .filter(|&&(_, exponent)| exponent > 0)
+            .map(|&(ref name, exponent)| term(name, exponent))
+            .collect();
+        let denominator: Vec<SymbolicManip<T>> = entries.iter()
+            .filter(|&&(_, exponent)| exponent < 0)
+            .map(|&(ref name, exponent)| term(name, -exponent))
+            .collect();
+
+        let numerator = product(numerator).unwrap_or_else(|| SymbolicManip::Number(T::one()));
+        match product(denominator) {
+            None => numerator,
+            Some(denominator) => numerator / denominator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_normalizes_to_a_power() {
+        let m_squared = c(1.0, "m") * c(1.0, "m");
+        assert_eq!(
+            m_squared.normalize_units().unit,
+            SymbolicManip::Symbol(String::from("m")).pow(SymbolicManip::Number(2.0))
+        );
+    }
+
+    #[test]
+    fn div_by_self_cancels_to_dimensionless() {
+        let dimensionless = c(6.0, "m") / c(2.0, "m");
+        assert_eq!(
+            dimensionless.normalize_units().unit,
+            SymbolicManip::Number(1.0)
+        );
+    }
+
+    #[test]
+    fn add_treats_structurally_different_but_equal_units_as_equal() {
+        // m * m and m ** 2 are different SymbolicManip trees but the same unit.
+        let a = Units { number: 3.0, unit: SymbolicManip::Symbol(String::from("m")) * SymbolicManip::Symbol(String::from("m")) };
+        let b = Units { number: 4.0, unit: SymbolicManip::Symbol(String::from("m")).pow(SymbolicManip::Number(2.0)) };
+        let sum = a + b;
+        assert_eq!(sum.number, 7.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mismatched units")]
+    fn add_panics_on_mismatched_units() {
+        let _ = c(1.0, "m") + c(1.0, "s");
+    }
+
+    fn c(num: f64, unit: &str) -> Units<f64> {
+        Units::new(num, String::from(unit))
+    }
 }
 